@@ -1,13 +1,44 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::{self, MaybeUninit};
 use std::ptr::{self, NonNull};
-use std::marker::PhantomData;
-use std::alloc::{realloc, alloc, Layout};
+use std::alloc::{realloc, alloc, dealloc, Layout};
+
+/// Total bytes reserved for `Array`'s small-array inline storage. This is a
+/// fixed budget, independent of `T`: unlike a naive `[MaybeUninit<T>; N]`
+/// (whose size scales with `T` and would make `Storage<T>` as large as its
+/// biggest possible inline buffer even for `T` that never uses it), a plain
+/// byte buffer keeps `Storage<T>` bounded for every `T`. The usable inline
+/// element count is computed per `T` from this byte budget below.
+const INLINE_BYTES: usize = 4;
+
+/// How many `T` fit within the fixed `INLINE_BYTES` budget, rounded down.
+/// `0` for any `T` bigger than the whole budget, which is what sends large
+/// `T` straight to the heap instead of ever touching inline storage.
+fn inline_capacity<T>() -> usize {
+    INLINE_BYTES / mem::size_of::<T>().max(1)
+}
+
+/// Inline storage's raw bytes. Aligned to a `usize` so pointer casts to `T`
+/// are valid for any `T` whose alignment fits within that budget.
+#[repr(align(8))]
+struct InlineBuf([MaybeUninit<u8>; INLINE_BYTES]);
+
+enum Storage<T> {
+    Inline(InlineBuf),
+    Heap(NonNull<T>),
+}
 
 /// A dynamically sized array implementation.
+///
+/// Small arrays (up to `inline_capacity::<T>()` elements of a small enough
+/// `T`) are stored inline and never touch the allocator; once an array grows
+/// past that it transparently spills to a heap allocation and behaves as
+/// before.
 pub struct Array<T: Sized> {
-    ptr: NonNull<T>,
+    storage: Storage<T>,
     capacity: usize,
     size: usize,
-    _m: PhantomData<T>
 }
 
 impl<T: Sized> Array<T> {
@@ -18,14 +49,22 @@ impl<T: Sized> Array<T> {
 
     /// Create an array with custom capacity.
     pub fn with_capacity(capacity: usize) -> Option<Array<T>> {
+        let inline_cap = inline_capacity::<T>();
+        if inline_cap > 0 && capacity <= inline_cap {
+            return Some(Array {
+                storage: Storage::Inline(InlineBuf(unsafe { MaybeUninit::uninit().assume_init() })),
+                size: 0,
+                capacity: inline_cap,
+            });
+        }
+
         let layout = Layout::array::<T>(capacity).ok()?;
         let ptr = unsafe { NonNull::new(alloc(layout) as *mut T)? };
 
         Some(Array {
-            ptr,
+            storage: Storage::Heap(ptr),
             size: 0,
             capacity,
-            _m: PhantomData::default(),
         })
     }
 
@@ -59,14 +98,12 @@ impl<T: Sized> Array<T> {
         self.remove(0)
     }
 
-    /// Gets the value at an index.
-    pub fn get(&self, idx: usize) -> Option<T> {
+    /// Borrows the value at an index without moving it out.
+    pub fn get(&self, idx: usize) -> Option<&T> {
         if idx >= self.size {
             return None;
         }
-        unsafe {
-            self.read_at(idx)
-        }
+        unsafe { Some(&*self.as_ptr().add(idx)) }
     }
 
     /// Set value at an index, returning the former value.
@@ -93,6 +130,33 @@ impl<T: Sized> Array<T> {
         self.capacity
     }
 
+    /// Raw pointer to the backing storage, for slice-based access by callers
+    /// that track their own live region (e.g. `ArrayQueue`'s ring).
+    pub fn as_ptr(&self) -> *const T {
+        match &self.storage {
+            Storage::Inline(buf) => buf.0.as_ptr() as *const T,
+            Storage::Heap(ptr) => ptr.as_ptr(),
+        }
+    }
+
+    /// Mutable raw pointer to the backing storage.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        match &mut self.storage {
+            Storage::Inline(buf) => buf.0.as_mut_ptr() as *mut T,
+            Storage::Heap(ptr) => ptr.as_ptr(),
+        }
+    }
+
+    /// Borrowing iterator over the elements, in index order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        unsafe { std::slice::from_raw_parts(self.as_ptr(), self.size) }.iter()
+    }
+
+    /// Mutably borrowing iterator over the elements, in index order.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr(), self.size) }.iter_mut()
+    }
+
     /// Add element at index 'index' in the array, pushing all elements with index > 'index' to the right.
     pub fn push(&mut self, index: usize, val: T) {
         self.adjust_size();
@@ -125,7 +189,7 @@ impl<T: Sized> Array<T> {
         if idx > self.capacity {
             panic!("Index error");
         }
-        ptr::write(self.ptr.as_ptr().add(idx), val);
+        ptr::write(self.as_mut_ptr().add(idx), val);
     }
 
     /// Read at location using inner pointer.
@@ -133,19 +197,52 @@ impl<T: Sized> Array<T> {
         if idx > self.capacity {
             return None;
         }
-        Some(ptr::read(self.ptr.as_ptr().add(idx)))
+        Some(ptr::read(self.as_ptr().add(idx)))
     }
 
+    /// Grows the backing storage to `new_capacity`, spilling from inline to
+    /// a heap allocation the first time it's called on an inline array and
+    /// behaving like a plain `realloc` afterwards.
     pub unsafe fn reallocate(&mut self, new_capacity: usize) {
-        let old_layout = Layout::array::<T>(self.capacity).unwrap();
-        self.capacity = new_capacity;
-        let layout = Layout::array::<T>(new_capacity).unwrap();
-        let new_ptr = unsafe { realloc(self.ptr.as_ptr() as *mut u8, old_layout, layout.size()) as *mut T };
-        self.ptr = NonNull::new(new_ptr).unwrap();
+        match &mut self.storage {
+            Storage::Inline(buf) => {
+                if new_capacity <= inline_capacity::<T>() {
+                    // Inline storage already has all the room it will ever have.
+                    return;
+                }
+
+                let layout = Layout::array::<T>(new_capacity).unwrap();
+                let new_ptr = NonNull::new(alloc(layout) as *mut T).unwrap();
+                // Copy the whole inline buffer, not just `self.size` elements:
+                // callers like `ArrayQueue` track their own live region and
+                // never update `Array`'s own size, so it can read `0` here
+                // even when every inline slot is actually occupied.
+                ptr::copy_nonoverlapping(buf.0.as_ptr() as *const T, new_ptr.as_ptr(), self.capacity);
+
+                self.storage = Storage::Heap(new_ptr);
+                self.capacity = new_capacity;
+            }
+            Storage::Heap(ptr) => {
+                let old_layout = Layout::array::<T>(self.capacity).unwrap();
+                let layout = Layout::array::<T>(new_capacity).unwrap();
+                let new_ptr = realloc(ptr.as_ptr() as *mut u8, old_layout, layout.size()) as *mut T;
+                *ptr = NonNull::new(new_ptr).unwrap();
+                self.capacity = new_capacity;
+            }
+        }
     }
 
     /// Grow and shrink array if needed by operation.
     fn adjust_size(&mut self) {
+        if matches!(self.storage, Storage::Inline(_)) {
+            // Inline storage has a fixed size; only spilling to the heap on
+            // growth is meaningful, there is nothing smaller to shrink to.
+            if self.size >= self.capacity {
+                unsafe { self.reallocate(self.capacity * 2) };
+            }
+            return;
+        }
+
         let new_capacity = if self.size >= self.capacity {
             self.capacity * 2
         } else if self.size < self.capacity / 2 {
@@ -160,9 +257,128 @@ impl<T: Sized> Array<T> {
     }
 }
 
+impl<T> Drop for Array<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            for i in 0..self.size {
+                ptr::drop_in_place(ptr.add(i));
+            }
+
+            if let Storage::Heap(heap_ptr) = &self.storage {
+                let layout = Layout::array::<T>(self.capacity).unwrap();
+                dealloc(heap_ptr.as_ptr() as *mut u8, layout);
+            }
+        }
+    }
+}
+
+/// Consuming iterator that drains an `Array` in index order.
+pub struct IntoIter<T> {
+    arr: Array<T>,
+    idx: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.idx >= self.arr.size {
+            return None;
+        }
+
+        let v = unsafe { ptr::read(self.arr.as_ptr().add(self.idx)) };
+        self.idx += 1;
+        Some(v)
+    }
+}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let ptr = self.arr.as_mut_ptr();
+            for i in self.idx..self.arr.size {
+                ptr::drop_in_place(ptr.add(i));
+            }
+        }
+        // The elements have all been moved out or dropped above; tell the
+        // backing `Array`'s own `Drop` not to touch them again.
+        self.arr.size = 0;
+    }
+}
+
+impl<T> IntoIterator for Array<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { arr: self, idx: 0 }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Array<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Array<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for Array<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut arr = Array::new().expect("allocation failed");
+        for item in iter {
+            arr.push_back(item);
+        }
+        arr
+    }
+}
+
+impl<T> Extend<T> for Array<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Array<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Array<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Array<T> {}
+
+impl<T: Hash> Hash for Array<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Array;
+    use std::mem;
 
     #[test]
     fn test_create() {
@@ -196,8 +412,8 @@ mod tests {
         arr.push_back(5);
 
         assert_eq!(arr.length(), 5);
-        assert_eq!(arr.get(0), Some(1));
-        assert_eq!(arr.get(4), Some(5));
+        assert_eq!(arr.get(0), Some(&1));
+        assert_eq!(arr.get(4), Some(&5));
     }
 
     #[test]
@@ -209,8 +425,8 @@ mod tests {
         arr.push_front(11);
 
 
-        assert_eq!(arr.get(0), Some(11));
-        assert_eq!(arr.get(1), Some(10));
+        assert_eq!(arr.get(0), Some(&11));
+        assert_eq!(arr.get(1), Some(&10));
     }
 
     #[test]
@@ -237,8 +453,8 @@ mod tests {
         arr.push_back(5);
         arr.set(2, 10);
 
-        assert_eq!(arr.get(2), Some(10));
-        assert_eq!(arr.get(4), Some(5));
+        assert_eq!(arr.get(2), Some(&10));
+        assert_eq!(arr.get(4), Some(&5));
     }
 
     #[test]
@@ -252,8 +468,8 @@ mod tests {
         let v = arr.remove(2);
 
         assert_eq!(arr.length(), 4);
-        assert_eq!(arr.get(arr.length() - 1), Some(5));
-        assert_eq!(arr.get(2), Some(4));
+        assert_eq!(arr.get(arr.length() - 1), Some(&5));
+        assert_eq!(arr.get(2), Some(&4));
         assert_eq!(v, Some(3));
     }
 
@@ -268,8 +484,8 @@ mod tests {
         let v = arr.remove(0);
 
         assert_eq!(arr.length(), 4);
-        assert_eq!(arr.get(arr.length() - 1), Some(5));
-        assert_eq!(arr.get(0), Some(2));
+        assert_eq!(arr.get(arr.length() - 1), Some(&5));
+        assert_eq!(arr.get(0), Some(&2));
         assert_eq!(v, Some(1));
     }
 
@@ -284,7 +500,97 @@ mod tests {
         let v = arr.remove(arr.length() - 1);
 
         assert_eq!(arr.length(), 4);
-        assert_eq!(arr.get(arr.length() - 1), Some(4));
+        assert_eq!(arr.get(arr.length() - 1), Some(&4));
         assert_eq!(v, Some(5));
     }
+
+    #[test]
+    fn test_iter() {
+        let mut arr = Array::<u8>::new().unwrap();
+        arr.push_back(1);
+        arr.push_back(2);
+        arr.push_back(3);
+
+        let collected: Vec<&u8> = arr.iter().collect();
+        assert_eq!(collected, vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut arr = Array::<String>::new().unwrap();
+        arr.push_back(String::from("a"));
+        arr.push_back(String::from("b"));
+
+        let collected: Vec<String> = arr.into_iter().collect();
+        assert_eq!(collected, vec![String::from("a"), String::from("b")]);
+    }
+
+    #[test]
+    fn test_from_iter_and_extend() {
+        let mut arr: Array<u8> = vec![1, 2, 3].into_iter().collect();
+        arr.extend(vec![4, 5]);
+
+        assert_eq!(arr.length(), 5);
+        assert_eq!(arr.get(4), Some(&5));
+    }
+
+    #[test]
+    fn test_debug_eq_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let a: Array<u8> = vec![1, 2, 3].into_iter().collect();
+        let b: Array<u8> = vec![1, 2, 3].into_iter().collect();
+        let c: Array<u8> = vec![1, 2].into_iter().collect();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(format!("{:?}", a), "[1, 2, 3]");
+
+        let hash_of = |arr: &Array<u8>| {
+            let mut hasher = DefaultHasher::new();
+            arr.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn test_small_array_stays_inline() {
+        // Not directly observable from the public API, but pushing and
+        // popping within `inline_capacity::<u8>()` (4) should never need a spill.
+        let mut arr = Array::<u8>::new().unwrap();
+        for i in 0..4 {
+            arr.push_back(i);
+        }
+        assert_eq!(arr.capacity, 4);
+        assert_eq!(arr.length(), 4);
+    }
+
+    #[test]
+    fn test_spills_to_heap_past_inline_capacity() {
+        let mut arr = Array::<u8>::new().unwrap();
+        for i in 0..10 {
+            arr.push_back(i);
+        }
+
+        assert_eq!(arr.length(), 10);
+        for i in 0..10 {
+            assert_eq!(arr.get(i), Some(&(i as u8)));
+        }
+    }
+
+    #[test]
+    fn test_storage_size_does_not_scale_with_large_t() {
+        // `Storage<T>`'s inline variant is a fixed byte buffer, not
+        // `[MaybeUninit<T>; N]`, so `Array<T>` must stay roughly
+        // pointer-sized even for a `T` that never uses inline storage at
+        // all (it's far bigger than `INLINE_BYTES`).
+        let baseline = mem::size_of::<Array<u8>>();
+        assert!(
+            mem::size_of::<Array<[u8; 1024]>>() <= baseline + 16,
+            "Array<[u8; 1024]> is {} bytes, Array<u8> is {baseline}",
+            mem::size_of::<Array<[u8; 1024]>>(),
+        );
+    }
 }
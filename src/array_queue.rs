@@ -1,3 +1,10 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+use std::ptr;
+
 use crate::array::Array;
 
 /// The Array Queue treating the backing Array as a Ring, controlling the first and last element
@@ -7,76 +14,596 @@ use crate::array::Array;
 /// ARR[J], ARR[J + 1], ... , ARR[(J + K) % len(ARR)].
 ///
 /// If size exceeds len(ARR) at insertion, we need to resize the backing array.
+///
+/// The ring layout is symmetric, so `ArrayQueue` supports pushing and
+/// popping at both ends in amortized O(1), like `VecDeque`.
+///
+/// The backing array's capacity is always kept a power of two, so every
+/// ring index is computed with `& self.mask` (`mask == capacity - 1`)
+/// instead of `% capacity`, trading a division for a bitwise and on every
+/// `push_back`/`pop_front`/`add_front`/`remove_back`.
 pub struct ArrayQueue<T> {
     arr: Array<T>,
     first_in: usize,
     size: usize,
+    mask: usize,
 }
 
 impl<T: Sized> ArrayQueue<T> {
     pub fn with_capacity(capacity: usize) -> Option<ArrayQueue<T>> {
-        Array::with_capacity(capacity).map(|arr| Self {
-            arr,
-            first_in: 0,
-            size: 0,
+        let capacity = capacity.max(1).next_power_of_two();
+        Array::with_capacity(capacity).map(|arr| {
+            let mask = arr.capacity() - 1;
+            Self {
+                arr,
+                first_in: 0,
+                size: 0,
+                mask,
+            }
         })
     }
 
     pub fn new() -> Option<ArrayQueue<T>> {
-        Array::new().map(|arr| Self {
-            arr,
-            first_in: 0,
-            size: 0,
-        })
+        Self::with_capacity(1)
     }
 
     pub fn length(&self) -> usize {
         self.size
     }
 
-    pub fn peek(&self) -> Option<T> {
+    /// Borrows the element at the front of the queue without removing it.
+    pub fn peek(&self) -> Option<&T> {
         if self.size == 0 {
             None
         } else {
-            unsafe { self.arr.read_at(self.first_in) }
+            unsafe { Some(&*self.arr.as_ptr().add(self.first_in)) }
         }
     }
 
-    pub fn remove(&mut self) -> Option<T> {
+    /// Removes and returns the element at the front of the queue.
+    pub fn pop_front(&mut self) -> Option<T> {
         if self.size == 0 {
             None
         } else {
             let v = unsafe { self.arr.read_at(self.first_in) };
-            self.first_in = (self.first_in + 1) % self.arr.capacity();
+            self.first_in = (self.first_in + 1) & self.mask;
             self.size -= 1;
 
             v
         }
     }
 
-    pub fn add(&mut self, val: T) {
+    /// Alias for [`ArrayQueue::pop_front`].
+    pub fn remove(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    /// Removes and returns the element at the back of the queue.
+    pub fn remove_back(&mut self) -> Option<T> {
+        if self.size == 0 {
+            None
+        } else {
+            let idx = (self.first_in + self.size - 1) & self.mask;
+            self.size -= 1;
+            unsafe { self.arr.read_at(idx) }
+        }
+    }
+
+    /// Resizes the backing array, preserving the ring's logical order, if
+    /// the queue is full. Shared by `push_back` and `add_front`.
+    fn grow_if_full(&mut self) {
         if self.size >= self.arr.capacity() {
-            let old_capacity = self.arr.capacity();
-            unsafe { self.arr.reallocate(2 * old_capacity) };
+            self.grow_to(2 * self.arr.capacity());
+        }
+    }
 
-            for i in 0..self.size {
-                let former_idx = (self.first_in + i) % old_capacity;
-                let new_idx = (self.first_in + i) % self.arr.capacity();
-                if former_idx != new_idx {
-                    unsafe {
-                        self.arr.write_at(new_idx, self.arr.read_at(former_idx).unwrap());
-                    }
+    /// Reallocates the backing array to `new_capacity` (a power of two),
+    /// unwrapping the ring so the logical order is preserved at the new size.
+    fn grow_to(&mut self, new_capacity: usize) {
+        let old_mask = self.mask;
+        unsafe { self.arr.reallocate(new_capacity) };
+        self.mask = self.arr.capacity() - 1;
+
+        for i in 0..self.size {
+            let former_idx = (self.first_in + i) & old_mask;
+            let new_idx = (self.first_in + i) & self.mask;
+            if former_idx != new_idx {
+                unsafe {
+                    self.arr.write_at(new_idx, self.arr.read_at(former_idx).unwrap());
                 }
             }
         }
+    }
+
+    /// Grows the backing array, if needed, so it has room for `additional`
+    /// more elements without another reallocation.
+    fn reserve(&mut self, additional: usize) {
+        let required = self.size + additional;
+        let mut new_capacity = self.arr.capacity();
+        while new_capacity < required {
+            new_capacity *= 2;
+        }
+        if new_capacity != self.arr.capacity() {
+            self.grow_to(new_capacity);
+        }
+    }
 
-        let dest_idx = (self.first_in + self.size) % self.arr.capacity();
+    /// Adds an element to the back of the queue.
+    pub fn push_back(&mut self, val: T) {
+        self.grow_if_full();
+
+        let dest_idx = (self.first_in + self.size) & self.mask;
         // Because we check that size <= capacity, we know dest_idx is within the array.
         unsafe {
             self.arr.write_at(dest_idx, val);
         }
         self.size += 1;
     }
+
+    /// Alias for [`ArrayQueue::push_back`].
+    pub fn add(&mut self, val: T) {
+        self.push_back(val)
+    }
+
+    /// Adds an element to the front of the queue.
+    pub fn add_front(&mut self, val: T) {
+        self.grow_if_full();
+
+        self.first_in = (self.first_in + self.mask) & self.mask;
+        unsafe {
+            self.arr.write_at(self.first_in, val);
+        }
+        self.size += 1;
+    }
+
+    /// View the live elements as two contiguous runs: the first is the
+    /// physical slice starting at `first_in`, the second is the wrap-around
+    /// remainder (empty if the ring does not currently wrap).
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        if self.size == 0 {
+            return (&[], &[]);
+        }
+
+        let cap = self.arr.capacity();
+        let first_len = (cap - self.first_in).min(self.size);
+        let second_len = self.size - first_len;
+
+        unsafe {
+            let first = std::slice::from_raw_parts(self.arr.as_ptr().add(self.first_in), first_len);
+            let second = std::slice::from_raw_parts(self.arr.as_ptr(), second_len);
+            (first, second)
+        }
+    }
+
+    /// Mutable counterpart to [`ArrayQueue::as_slices`].
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        if self.size == 0 {
+            return (&mut [], &mut []);
+        }
+
+        let cap = self.arr.capacity();
+        let first_len = (cap - self.first_in).min(self.size);
+        let second_len = self.size - first_len;
+
+        unsafe {
+            let first = std::slice::from_raw_parts_mut(self.arr.as_mut_ptr().add(self.first_in), first_len);
+            let second = std::slice::from_raw_parts_mut(self.arr.as_mut_ptr(), second_len);
+            (first, second)
+        }
+    }
+
+    /// Rearrange the backing array so `first_in` becomes `0` and the whole
+    /// queue is a single contiguous slice, then return that slice.
+    ///
+    /// Only the live `size` elements of the ring are ever touched: the rest
+    /// of the backing capacity may be uninitialized, so the move is staged
+    /// through a scratch `Array` sized to exactly the live region rather
+    /// than rotated in place over the full `[0, capacity)` range.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.first_in != 0 && self.size > 0 {
+            let mut staging = Array::<T>::with_capacity(self.size).expect("allocation failed");
+            for i in 0..self.size {
+                let idx = (self.first_in + i) & self.mask;
+                unsafe {
+                    staging.write_at(i, self.arr.read_at(idx).unwrap());
+                }
+            }
+            for i in 0..self.size {
+                unsafe {
+                    self.arr.write_at(i, staging.read_at(i).unwrap());
+                }
+            }
+            self.first_in = 0;
+        } else if self.size == 0 {
+            self.first_in = 0;
+        }
+
+        unsafe { std::slice::from_raw_parts_mut(self.arr.as_mut_ptr(), self.size) }
+    }
+
+    /// Borrowing iterator over the elements, in front-to-back queue order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            queue: self,
+            idx: 0,
+            end: self.size,
+        }
+    }
+
+    /// Mutably borrowing iterator over the elements, in front-to-back queue order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            ptr: self.arr.as_mut_ptr(),
+            mask: self.mask,
+            first_in: self.first_in,
+            idx: 0,
+            end: self.size,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Remove and yield the logical elements in `range`, closing the gap
+    /// they leave behind once the returned `Drain` is dropped.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.size,
+        };
+
+        assert!(start <= end && end <= self.size, "drain range out of bounds");
+
+        Drain {
+            queue: self,
+            start,
+            end,
+            idx: start,
+            back: end,
+        }
+    }
+}
+
+impl<T> Drop for ArrayQueue<T> {
+    fn drop(&mut self) {
+        for i in 0..self.size {
+            let idx = (self.first_in + i) & self.mask;
+            unsafe {
+                ptr::drop_in_place(self.arr.as_mut_ptr().add(idx));
+            }
+        }
+    }
+}
+
+/// Borrowing iterator over `ArrayQueue`, walking the ring in logical
+/// front-to-back order.
+pub struct Iter<'a, T> {
+    queue: &'a ArrayQueue<T>,
+    idx: usize,
+    end: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.idx >= self.end {
+            return None;
+        }
+
+        let phys = (self.queue.first_in + self.idx) & self.queue.mask;
+        self.idx += 1;
+        Some(unsafe { &*self.queue.arr.as_ptr().add(phys) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.idx;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.idx >= self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        let phys = (self.queue.first_in + self.end) & self.queue.mask;
+        Some(unsafe { &*self.queue.arr.as_ptr().add(phys) })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+/// Mutably borrowing iterator over `ArrayQueue`, walking the ring in logical
+/// front-to-back order.
+pub struct IterMut<'a, T> {
+    ptr: *mut T,
+    mask: usize,
+    first_in: usize,
+    idx: usize,
+    end: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.idx >= self.end {
+            return None;
+        }
+
+        let phys = (self.first_in + self.idx) & self.mask;
+        self.idx += 1;
+        Some(unsafe { &mut *self.ptr.add(phys) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.idx;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.idx >= self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        let phys = (self.first_in + self.end) & self.mask;
+        Some(unsafe { &mut *self.ptr.add(phys) })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+/// Removes the logical range `[start, end)` from an `ArrayQueue`, yielding
+/// each element as it is read out. Dropping the guard (whether or not it was
+/// fully consumed) closes the gap by shifting whichever side — the elements
+/// before `start` or the elements after `end` — is cheaper to move, the same
+/// heuristic as the indexed `add`/`remove` in `array_deque.rs`.
+pub struct Drain<'a, T> {
+    queue: &'a mut ArrayQueue<T>,
+    start: usize,
+    end: usize,
+    idx: usize,
+    back: usize,
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.idx >= self.back {
+            return None;
+        }
+
+        let phys = (self.queue.first_in + self.idx) & self.queue.mask;
+        self.idx += 1;
+        unsafe { self.queue.arr.read_at(phys) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.idx;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.idx >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        let phys = (self.queue.first_in + self.back) & self.queue.mask;
+        unsafe { self.queue.arr.read_at(phys) }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {}
+
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        let mask = self.queue.mask;
+
+        // Drop whatever the caller left unconsumed.
+        for i in self.idx..self.back {
+            let phys = (self.queue.first_in + i) & mask;
+            unsafe {
+                ptr::drop_in_place(self.queue.arr.as_mut_ptr().add(phys));
+            }
+        }
+
+        let drained = self.end - self.start;
+        let head_len = self.start;
+        let tail_len = self.queue.size - self.end;
+
+        if head_len <= tail_len {
+            // Shift the elements before the gap forward to close it.
+            for i in (0..head_len).rev() {
+                let src = (self.queue.first_in + i) & mask;
+                let dst = (self.queue.first_in + i + drained) & mask;
+                unsafe {
+                    let v = self.queue.arr.read_at(src).unwrap();
+                    self.queue.arr.write_at(dst, v);
+                }
+            }
+            self.queue.first_in = (self.queue.first_in + drained) & mask;
+        } else {
+            // Shift the elements after the gap backward to close it.
+            for i in self.end..self.queue.size {
+                let src = (self.queue.first_in + i) & mask;
+                let dst = (self.queue.first_in + i - drained) & mask;
+                unsafe {
+                    let v = self.queue.arr.read_at(src).unwrap();
+                    self.queue.arr.write_at(dst, v);
+                }
+            }
+        }
+
+        self.queue.size -= drained;
+    }
+}
+
+/// Consuming iterator that drains an `ArrayQueue` in front-to-back order.
+pub struct IntoIter<T> {
+    queue: ArrayQueue<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.queue.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.queue.length();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.queue.remove_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for ArrayQueue<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { queue: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArrayQueue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ArrayQueue<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for ArrayQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = ArrayQueue::new().expect("allocation failed");
+        for item in iter {
+            queue.push_back(item);
+        }
+        queue
+    }
+}
+
+impl<T> Extend<T> for ArrayQueue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArrayQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ArrayQueue<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for ArrayQueue<T> {}
+
+impl<T: Hash> Hash for ArrayQueue<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl io::Write for ArrayQueue<u8> {
+    /// Appends `buf` to the back of the queue, growing the backing array at
+    /// most once up front via `reserve` rather than mid-copy, then bulk
+    /// copying into the (at most two) contiguous tail runs.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.reserve(buf.len());
+
+        let cap = self.arr.capacity();
+        let mut written = 0;
+        while written < buf.len() {
+            let dest_idx = (self.first_in + self.size) & self.mask;
+            let run_len = (cap - dest_idx).min(buf.len() - written);
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    buf.as_ptr().add(written),
+                    self.arr.as_mut_ptr().add(dest_idx),
+                    run_len,
+                );
+            }
+            self.size += run_len;
+            written += run_len;
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl io::Read for ArrayQueue<u8> {
+    /// Drains up to `buf.len()` bytes from the front of the queue into `buf`,
+    /// bulk copying from the (at most two) contiguous front runs.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let cap = self.arr.capacity();
+        let to_read = buf.len().min(self.size);
+
+        let mut read = 0;
+        while read < to_read {
+            let src_idx = (self.first_in + read) & self.mask;
+            let run_len = (cap - src_idx).min(to_read - read);
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    self.arr.as_ptr().add(src_idx),
+                    buf.as_mut_ptr().add(read),
+                    run_len,
+                );
+            }
+            read += run_len;
+        }
+
+        self.first_in = (self.first_in + to_read) & self.mask;
+        self.size -= to_read;
+
+        Ok(to_read)
+    }
 }
 
 #[cfg(test)]
@@ -89,6 +616,65 @@ mod tests {
         assert_eq!(queue.length(), 0);
     }
 
+    #[test]
+    fn test_capacity_rounds_up_to_power_of_two() {
+        // capacity 5 has no exact power-of-two representation, so it rounds
+        // up to 8; a request that's already a power of two (8) is left
+        // alone. Both requests exceed `Array`'s small-array inline capacity
+        // (4), so the backing `Array`'s own capacity can't coincidentally
+        // mask a rounding bug here.
+        let rounded_up = ArrayQueue::<u8>::with_capacity(5).unwrap();
+        assert_eq!(rounded_up.length(), 0);
+        assert_eq!(rounded_up.mask + 1, 8);
+
+        let exact = ArrayQueue::<u8>::with_capacity(8).unwrap();
+        assert_eq!(exact.length(), 0);
+        assert_eq!(exact.mask + 1, 8);
+    }
+
+    #[test]
+    fn test_wraparound_at_capacity_one() {
+        // `u64` is wider than `Array`'s inline budget, so the backing array
+        // really has the requested capacity instead of the inline one.
+        let mut queue = ArrayQueue::<u64>::with_capacity(1).unwrap();
+        for i in 0..5 {
+            queue.add(i);
+            assert_eq!(queue.remove(), Some(i));
+        }
+        assert_eq!(queue.length(), 0);
+    }
+
+    #[test]
+    fn test_wraparound_at_capacity_two() {
+        let mut queue = ArrayQueue::<u64>::with_capacity(2).unwrap();
+
+        queue.add(1);
+        queue.add(2);
+        assert_eq!(queue.remove(), Some(1));
+        queue.add(3);
+        assert_eq!(queue.remove(), Some(2));
+        assert_eq!(queue.remove(), Some(3));
+        assert_eq!(queue.remove(), None);
+    }
+
+    #[test]
+    fn test_wraparound_after_several_doublings() {
+        let mut queue = ArrayQueue::<u64>::with_capacity(1).unwrap();
+
+        // Push past several power-of-two growth steps (1 -> 2 -> 4 -> 8 -> 16),
+        // interleaving removes so `first_in` wraps around the backing array
+        // more than once.
+        for round in 0..20u64 {
+            queue.add(round);
+            if round % 3 == 0 {
+                queue.remove();
+            }
+        }
+
+        let remaining: Vec<u64> = std::iter::from_fn(|| queue.remove()).collect();
+        assert_eq!(remaining, vec![7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19]);
+    }
+
     #[test]
     fn test_resize() {
         let mut queue = ArrayQueue::<u8>::new().unwrap();
@@ -135,12 +721,65 @@ mod tests {
         let v3 = queue.remove();
 
         assert_eq!(queue.length(), 2);
-        assert_eq!(queue.peek(), Some(4));
+        assert_eq!(queue.peek(), Some(&4));
         assert_eq!(v1, Some(1));
         assert_eq!(v2, Some(2));
         assert_eq!(v3, Some(3));
     }
 
+    #[test]
+    fn test_add_front() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(2);
+        queue.add(3);
+        queue.add_front(1);
+        queue.add_front(0);
+
+        let collected: Vec<&u8> = queue.iter().collect();
+        assert_eq!(collected, vec![&0, &1, &2, &3]);
+    }
+
+    #[test]
+    fn test_add_front_resizes_when_full() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(8).unwrap();
+        for i in 0..8 {
+            queue.add_front(i);
+        }
+        queue.add_front(8);
+
+        assert_eq!(queue.length(), 9);
+        let collected: Vec<&u8> = queue.iter().collect();
+        assert_eq!(collected, vec![&8, &7, &6, &5, &4, &3, &2, &1, &0]);
+    }
+
+    #[test]
+    fn test_remove_back() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+
+        assert_eq!(queue.remove_back(), Some(3));
+        assert_eq!(queue.remove_back(), Some(2));
+        assert_eq!(queue.length(), 1);
+        assert_eq!(queue.remove_back(), Some(1));
+        assert_eq!(queue.remove_back(), None);
+    }
+
+    #[test]
+    fn test_double_ended_mix() {
+        let mut queue = ArrayQueue::<u8>::new().unwrap();
+        queue.push_back(2);
+        queue.add_front(1);
+        queue.push_back(3);
+        queue.add_front(0);
+
+        assert_eq!(queue.pop_front(), Some(0));
+        assert_eq!(queue.remove_back(), Some(3));
+        let remaining: Vec<&u8> = queue.iter().collect();
+        assert_eq!(remaining, vec![&1, &2]);
+    }
+
     #[test]
     fn test_add() {
         let mut queue = ArrayQueue::<u8>::with_capacity(10).unwrap();
@@ -151,6 +790,494 @@ mod tests {
         queue.add(5);
 
         assert_eq!(queue.length(), 5);
-        assert_eq!(queue.peek(), Some(1));
+        assert_eq!(queue.peek(), Some(&1));
+    }
+
+    #[test]
+    fn test_as_slices_contiguous() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+
+        let (first, second) = queue.as_slices();
+        assert_eq!(first, &[1, 2, 3]);
+        assert_eq!(second, &[] as &[u8]);
+    }
+
+    #[test]
+    fn test_as_slices_wrapped() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+        queue.add(4);
+
+        queue.remove();
+        queue.remove();
+        queue.add(5);
+        queue.add(6);
+
+        let (first, second) = queue.as_slices();
+        assert_eq!(first, &[3, 4]);
+        assert_eq!(second, &[5, 6]);
+    }
+
+    #[test]
+    fn test_as_mut_slices_wrapped() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+        queue.add(4);
+
+        queue.remove();
+        queue.remove();
+        queue.add(5);
+        queue.add(6);
+
+        {
+            let (first, second) = queue.as_mut_slices();
+            for v in first.iter_mut().chain(second.iter_mut()) {
+                *v *= 10;
+            }
+        }
+
+        let (first, second) = queue.as_slices();
+        assert_eq!(first, &[30, 40]);
+        assert_eq!(second, &[50, 60]);
+    }
+
+    #[test]
+    fn test_make_contiguous() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+        queue.add(4);
+
+        queue.remove();
+        queue.remove();
+        queue.add(5);
+        queue.add(6);
+
+        assert_eq!(queue.make_contiguous(), &[3, 4, 5, 6]);
+        assert_eq!(queue.as_slices(), (&[3, 4, 5, 6][..], &[][..]));
+    }
+
+    #[test]
+    fn test_make_contiguous_with_spare_capacity() {
+        // Backing capacity (8) is larger than the live region (3 elements),
+        // so the unused slots are never initialized; make_contiguous must
+        // not touch them.
+        let mut queue = ArrayQueue::<u8>::with_capacity(8).unwrap();
+        for i in 1..=6 {
+            queue.add(i);
+        }
+        queue.remove();
+        queue.remove();
+        queue.remove();
+        // Logical order is now [4, 5, 6], physically offset at first_in == 3.
+
+        assert_eq!(queue.make_contiguous(), &[4, 5, 6]);
+        assert_eq!(queue.as_slices(), (&[4, 5, 6][..], &[][..]));
+    }
+
+    #[test]
+    fn test_make_contiguous_drops_non_copy_elements_exactly_once() {
+        let count = std::rc::Rc::new(std::cell::Cell::new(0));
+
+        let mut queue = ArrayQueue::<DropCounter>::with_capacity(8).unwrap();
+        for _ in 0..6 {
+            queue.add(DropCounter(count.clone()));
+        }
+        queue.remove();
+        queue.remove();
+        queue.remove();
+
+        let _ = queue.make_contiguous();
+        assert_eq!(queue.length(), 3);
+        assert_eq!(count.get(), 3);
+
+        drop(queue);
+        assert_eq!(count.get(), 6);
+    }
+
+    #[test]
+    fn test_non_copy_elements_are_owned_not_duplicated() {
+        let mut queue = ArrayQueue::<String>::with_capacity(2).unwrap();
+
+        queue.add(String::from("a"));
+        queue.add(String::from("b"));
+        queue.add(String::from("c"));
+        queue.add(String::from("d"));
+        queue.add(String::from("e")); // forces a second heap resize (2 -> 4 -> 8)
+
+        assert_eq!(queue.peek(), Some(&String::from("a")));
+        assert_eq!(queue.remove(), Some(String::from("a")));
+        assert_eq!(queue.remove(), Some(String::from("b")));
+        assert_eq!(queue.remove(), Some(String::from("c")));
+        assert_eq!(queue.remove(), Some(String::from("d")));
+        assert_eq!(queue.remove(), Some(String::from("e")));
+        assert_eq!(queue.remove(), None);
+    }
+
+    #[test]
+    fn test_drop_with_elements_remaining() {
+        // Dropping a non-empty queue of non-Copy elements should not leak or
+        // double free; Miri (or a sanitizer) is what actually catches a
+        // regression here, but the test still exercises the path.
+        let mut queue = ArrayQueue::<String>::with_capacity(4).unwrap();
+        queue.add(String::from("a"));
+        queue.add(String::from("b"));
+        queue.remove();
+        queue.add(String::from("c"));
+
+        drop(queue);
+    }
+
+    /// Counts live drops via a shared cell, so tests can assert each element
+    /// is dropped exactly once rather than relying on a sanitizer to catch
+    /// a double-free or a leak.
+    struct DropCounter(std::rc::Rc<std::cell::Cell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_drop_counter_drains_exactly_once() {
+        let count = std::rc::Rc::new(std::cell::Cell::new(0));
+
+        let mut queue = ArrayQueue::<DropCounter>::with_capacity(4).unwrap();
+        for _ in 0..3 {
+            queue.add(DropCounter(count.clone()));
+        }
+
+        while queue.remove().is_some() {}
+        assert_eq!(count.get(), 3);
+    }
+
+    #[test]
+    fn test_drop_counter_drops_remaining_on_queue_drop() {
+        let count = std::rc::Rc::new(std::cell::Cell::new(0));
+
+        let mut queue = ArrayQueue::<DropCounter>::with_capacity(4).unwrap();
+        queue.add(DropCounter(count.clone()));
+        queue.add(DropCounter(count.clone()));
+        queue.remove();
+        queue.add(DropCounter(count.clone()));
+
+        assert_eq!(count.get(), 1);
+        drop(queue);
+        assert_eq!(count.get(), 3);
+    }
+
+    #[test]
+    fn test_iter_honors_wrap() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+        queue.add(4);
+
+        queue.remove();
+        queue.remove();
+        queue.add(5);
+        queue.add(6);
+
+        let collected: Vec<&u8> = queue.iter().collect();
+        assert_eq!(collected, vec![&3, &4, &5, &6]);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+
+        for v in queue.iter_mut() {
+            *v *= 10;
+        }
+
+        let collected: Vec<&u8> = queue.iter().collect();
+        assert_eq!(collected, vec![&10, &20, &30]);
+    }
+
+    #[test]
+    fn test_into_iter_drains_in_order() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+        queue.add(4);
+        queue.remove();
+        queue.add(5);
+
+        let collected: Vec<u8> = queue.into_iter().collect();
+        assert_eq!(collected, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_iter_is_double_ended_and_exact_size() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+        queue.add(4);
+
+        let mut iter = queue.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_mut_is_double_ended() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+
+        {
+            let mut iter = queue.iter_mut();
+            *iter.next().unwrap() *= 10;
+            *iter.next_back().unwrap() += 10;
+        }
+
+        let collected: Vec<&u8> = queue.iter().collect();
+        assert_eq!(collected, vec![&10, &2, &13]);
+    }
+
+    #[test]
+    fn test_into_iter_is_double_ended_and_exact_size() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+        queue.add(4);
+
+        let mut iter = queue.into_iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.collect::<Vec<u8>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_drain_is_double_ended_and_exact_size() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(8).unwrap();
+        for i in 1..=5 {
+            queue.add(i);
+        }
+
+        let mut drain = queue.drain(0..5);
+        assert_eq!(drain.len(), 5);
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.next_back(), Some(5));
+        assert_eq!(drain.next_back(), Some(4));
+        assert_eq!(drain.collect::<Vec<u8>>(), vec![2, 3]);
+
+        assert_eq!(queue.length(), 0);
+    }
+
+    #[test]
+    fn test_from_iter_and_extend() {
+        let mut queue: ArrayQueue<u8> = vec![1, 2, 3].into_iter().collect();
+        queue.extend(vec![4, 5]);
+
+        assert_eq!(queue.length(), 5);
+        let collected: Vec<&u8> = queue.iter().collect();
+        assert_eq!(collected, vec![&1, &2, &3, &4, &5]);
+    }
+
+    #[test]
+    fn test_debug_eq_hash_ignore_physical_offset() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut a = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        a.add(1);
+        a.add(2);
+        a.add(3);
+
+        // Same logical contents as `a`, but at a different `first_in`
+        // offset (1) after removing one element from the front.
+        let mut b = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        b.add(0);
+        b.add(1);
+        b.add(2);
+        b.add(3);
+        b.remove();
+
+        assert_eq!(a, b);
+        assert_eq!(format!("{:?}", a), "[1, 2, 3]");
+
+        let hash_of = |queue: &ArrayQueue<u8>| {
+            let mut hasher = DefaultHasher::new();
+            queue.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn test_drain_middle_shifts_shorter_tail() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(8).unwrap();
+        for i in 1..=5 {
+            queue.add(i);
+        }
+
+        // Two elements before the gap, one after it: the tail is shifted.
+        let drained: Vec<u8> = queue.drain(2..4).collect();
+        assert_eq!(drained, vec![3, 4]);
+
+        assert_eq!(queue.length(), 3);
+        let remaining: Vec<&u8> = queue.iter().collect();
+        assert_eq!(remaining, vec![&1, &2, &5]);
+    }
+
+    #[test]
+    fn test_drain_start_shifts_shorter_head() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(8).unwrap();
+        for i in 1..=5 {
+            queue.add(i);
+        }
+
+        let drained: Vec<u8> = queue.drain(0..1).collect();
+        assert_eq!(drained, vec![1]);
+
+        assert_eq!(queue.length(), 4);
+        let remaining: Vec<&u8> = queue.iter().collect();
+        assert_eq!(remaining, vec![&2, &3, &4, &5]);
+    }
+
+    #[test]
+    fn test_drain_across_wrap() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.add(1);
+        queue.add(2);
+        queue.add(3);
+        queue.add(4);
+        queue.remove();
+        queue.remove();
+        queue.add(5);
+        queue.add(6);
+        // Logical order is now [3, 4, 5, 6], wrapped physically.
+
+        let drained: Vec<u8> = queue.drain(1..3).collect();
+        assert_eq!(drained, vec![4, 5]);
+
+        assert_eq!(queue.length(), 2);
+        let remaining: Vec<&u8> = queue.iter().collect();
+        assert_eq!(remaining, vec![&3, &6]);
+    }
+
+    #[test]
+    fn test_drain_early_drop_still_closes_gap() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(8).unwrap();
+        for i in 1..=5 {
+            queue.add(i);
+        }
+
+        {
+            let mut drain = queue.drain(1..4);
+            assert_eq!(drain.next(), Some(2)); // only partially consumed
+        }
+
+        assert_eq!(queue.length(), 2);
+        let remaining: Vec<&u8> = queue.iter().collect();
+        assert_eq!(remaining, vec![&1, &5]);
+    }
+
+    #[test]
+    fn test_write_then_read_round_trip() {
+        use std::io::{Read, Write};
+
+        let mut queue = ArrayQueue::<u8>::with_capacity(8).unwrap();
+        assert_eq!(queue.write(&[1, 2, 3, 4]).unwrap(), 4);
+        assert_eq!(queue.length(), 4);
+
+        let mut out = [0u8; 4];
+        assert_eq!(queue.read(&mut out).unwrap(), 4);
+        assert_eq!(out, [1, 2, 3, 4]);
+        assert_eq!(queue.length(), 0);
+    }
+
+    #[test]
+    fn test_write_wraps_across_end_of_backing_array() {
+        use std::io::{Read, Write};
+
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.write_all(&[1, 2, 3]).unwrap();
+        let mut discard = [0u8; 2];
+        queue.read_exact(&mut discard).unwrap();
+        // first_in is now 2; writing 3 more bytes has to wrap around slot 4.
+        queue.write_all(&[4, 5, 6]).unwrap();
+
+        let mut out = [0u8; 4];
+        assert_eq!(queue.read(&mut out).unwrap(), 4);
+        assert_eq!(out, [3, 4, 5, 6]);
+        assert_eq!(queue.length(), 0);
+    }
+
+    #[test]
+    fn test_write_grows_backing_array_when_needed() {
+        use std::io::Write;
+
+        let mut queue = ArrayQueue::<u8>::with_capacity(2).unwrap();
+        queue.write_all(&[1, 2, 3, 4, 5]).unwrap();
+
+        assert_eq!(queue.length(), 5);
+        let collected: Vec<&u8> = queue.iter().collect();
+        assert_eq!(collected, vec![&1, &2, &3, &4, &5]);
+    }
+
+    #[test]
+    fn test_read_stops_at_queue_length() {
+        use std::io::{Read, Write};
+
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        queue.write_all(&[1, 2]).unwrap();
+
+        let mut out = [0u8; 4];
+        assert_eq!(queue.read(&mut out).unwrap(), 2);
+        assert_eq!(&out[..2], &[1, 2]);
+        assert_eq!(queue.length(), 0);
+    }
+
+    #[test]
+    fn test_io_copy_from_reader_into_queue() {
+        let mut queue = ArrayQueue::<u8>::with_capacity(4).unwrap();
+        let mut source: &[u8] = &[1, 2, 3, 4, 5, 6];
+        let copied = std::io::copy(&mut source, &mut queue).unwrap();
+
+        assert_eq!(copied, 6);
+        assert_eq!(queue.length(), 6);
+    }
+
+    #[test]
+    fn test_drain_drops_non_copy_elements() {
+        let mut queue = ArrayQueue::<String>::with_capacity(4).unwrap();
+        queue.add(String::from("a"));
+        queue.add(String::from("b"));
+        queue.add(String::from("c"));
+
+        {
+            let mut drain = queue.drain(0..2);
+            assert_eq!(drain.next(), Some(String::from("a")));
+            // "b" is dropped here without being consumed.
+        }
+
+        assert_eq!(queue.length(), 1);
+        assert_eq!(queue.peek(), Some(&String::from("c")));
     }
 }
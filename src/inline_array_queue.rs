@@ -0,0 +1,202 @@
+use std::mem::MaybeUninit;
+
+/// A fixed-capacity FIFO queue backed by an inline `[MaybeUninit<T>; N]`.
+///
+/// Unlike `ArrayQueue`, this type never allocates on the heap and never
+/// resizes: its capacity is chosen at the type level via the const generic
+/// `N`, which makes it usable in `no_std`/embedded contexts where a hard
+/// upper bound on queued items is known ahead of time. Because the buffer
+/// cannot grow, `size == N` is enough to tell a full queue from an empty one,
+/// and `enqueue` reports a full queue by handing the value back instead of
+/// panicking or reallocating.
+///
+/// Uses the same ring layout as `ArrayQueue`: if `first_in` is J and `size`
+/// is K, the elements are at `BUF[J], BUF[J + 1], ..., BUF[(J + K - 1) % N]`.
+pub struct InlineArrayQueue<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    first_in: usize,
+    size: usize,
+}
+
+/// Alias for [`InlineArrayQueue`] under the name more commonly used for this
+/// kind of bounded, allocation-free ring buffer.
+pub type StaticArrayQueue<T, const N: usize> = InlineArrayQueue<T, N>;
+
+impl<T, const N: usize> InlineArrayQueue<T, N> {
+    /// Create a new, empty queue.
+    pub fn new() -> Self {
+        Self {
+            buf: unsafe { MaybeUninit::uninit().assume_init() },
+            first_in: 0,
+            size: 0,
+        }
+    }
+
+    /// The fixed capacity of the queue, chosen at the type level.
+    pub const fn capacity() -> usize {
+        N
+    }
+
+    /// How many elements are currently queued.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Whether the queue holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Whether the queue is at capacity and cannot accept another element.
+    pub fn is_full(&self) -> bool {
+        self.size == N
+    }
+
+    fn mod_index(&self, idx: usize) -> usize {
+        (self.first_in + idx) % N
+    }
+
+    /// Borrow the element at the front of the queue without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        if self.size == 0 {
+            None
+        } else {
+            Some(unsafe { &*self.buf[self.first_in].as_ptr() })
+        }
+    }
+
+    /// Remove and return the element at the front of the queue.
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.size == 0 {
+            None
+        } else {
+            let v = unsafe { self.buf[self.first_in].as_ptr().read() };
+            self.first_in = self.mod_index(1);
+            self.size -= 1;
+            Some(v)
+        }
+    }
+
+    /// Add an element to the back of the queue.
+    ///
+    /// Returns `val` back to the caller if the queue is already full, since
+    /// there is no backing store to grow into.
+    pub fn enqueue(&mut self, val: T) -> Result<(), T> {
+        if self.size == N {
+            return Err(val);
+        }
+
+        let dest_idx = self.mod_index(self.size);
+        self.buf[dest_idx] = MaybeUninit::new(val);
+        self.size += 1;
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> Default for InlineArrayQueue<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for InlineArrayQueue<T, N> {
+    fn drop(&mut self) {
+        for i in 0..self.size {
+            let idx = self.mod_index(i);
+            unsafe {
+                self.buf[idx].assume_init_drop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InlineArrayQueue;
+
+    #[test]
+    fn test_create() {
+        let queue = InlineArrayQueue::<u8, 4>::new();
+        assert_eq!(queue.len(), 0);
+        assert_eq!(InlineArrayQueue::<u8, 4>::capacity(), 4);
+    }
+
+    #[test]
+    fn test_enqueue_dequeue() {
+        let mut queue = InlineArrayQueue::<u8, 4>::new();
+
+        assert_eq!(queue.enqueue(1), Ok(()));
+        assert_eq!(queue.enqueue(2), Ok(()));
+        assert_eq!(queue.enqueue(3), Ok(()));
+
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.peek(), Some(&1));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn test_full_returns_value() {
+        let mut queue = InlineArrayQueue::<u8, 2>::new();
+
+        assert_eq!(queue.enqueue(1), Ok(()));
+        assert_eq!(queue.enqueue(2), Ok(()));
+        assert!(queue.is_full());
+        assert_eq!(queue.enqueue(3), Err(3));
+    }
+
+    #[test]
+    fn test_wraparound() {
+        let mut queue = InlineArrayQueue::<u8, 3>::new();
+
+        queue.enqueue(1).unwrap();
+        queue.enqueue(2).unwrap();
+        queue.dequeue();
+        queue.enqueue(3).unwrap();
+        queue.enqueue(4).unwrap();
+
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn test_static_array_queue_alias() {
+        use super::StaticArrayQueue;
+
+        let mut queue = StaticArrayQueue::<u8, 2>::new();
+        assert_eq!(queue.enqueue(1), Ok(()));
+        assert_eq!(queue.enqueue(2), Ok(()));
+        assert_eq!(queue.enqueue(3), Err(3));
+        assert_eq!(queue.dequeue(), Some(1));
+    }
+
+    /// Counts live drops via a shared cell, mirroring the `DropCounter` used
+    /// in `array_queue.rs`'s tests.
+    struct DropCounter(std::rc::Rc<std::cell::Cell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_drop_counter_drops_remaining_on_queue_drop() {
+        let count = std::rc::Rc::new(std::cell::Cell::new(0));
+
+        let mut queue = InlineArrayQueue::<DropCounter, 4>::new();
+        let _ = queue.enqueue(DropCounter(count.clone()));
+        let _ = queue.enqueue(DropCounter(count.clone()));
+        queue.dequeue();
+        let _ = queue.enqueue(DropCounter(count.clone()));
+
+        assert_eq!(count.get(), 1);
+        drop(queue);
+        assert_eq!(count.get(), 3);
+    }
+}
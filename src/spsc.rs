@@ -0,0 +1,196 @@
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// A fixed-capacity ring buffer shared between exactly one [`Producer`] and
+/// one [`Consumer`], each of which may live on its own thread and
+/// communicate without ever taking a lock.
+///
+/// `head` is only ever written by the consumer and `tail` only by the
+/// producer; each side reads the other's index with `Acquire` and publishes
+/// its own with `Release`, which is enough to make every enqueued element
+/// visible to the consumer that later reads it. One slot is sacrificed so a
+/// full buffer (`(tail + 1) % N == head`) can be told apart from an empty
+/// one (`tail == head`) without a separate flag — the buffer therefore holds
+/// at most `N - 1` elements.
+struct Shared<T, const N: usize> {
+    buf: UnsafeCell<[MaybeUninit<T>; N]>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// `UnsafeCell` is never `Sync` on its own; `head`/`tail` are what actually
+// synchronize access to `buf`, so it's safe to share `Shared` across the
+// producer/consumer thread pair as long as `T` itself is `Send`.
+unsafe impl<T: Send, const N: usize> Sync for Shared<T, N> {}
+
+impl<T, const N: usize> Drop for Shared<T, N> {
+    fn drop(&mut self) {
+        let mut head = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+        let buf = self.buf.get_mut();
+
+        while head != tail {
+            unsafe { buf[head].assume_init_drop() };
+            head = (head + 1) % N;
+        }
+    }
+}
+
+/// The sending half of an [`spsc`] queue.
+pub struct Producer<T, const N: usize> {
+    shared: Arc<Shared<T, N>>,
+}
+
+/// The receiving half of an [`spsc`] queue.
+pub struct Consumer<T, const N: usize> {
+    shared: Arc<Shared<T, N>>,
+}
+
+/// Creates a lock-free, single-producer/single-consumer ring buffer with
+/// room for `N - 1` elements, returning its two halves.
+pub fn spsc<T, const N: usize>() -> (Producer<T, N>, Consumer<T, N>) {
+    assert!(N >= 2, "spsc buffer needs at least 2 slots to hold 1 element");
+
+    let shared = Arc::new(Shared {
+        buf: UnsafeCell::new(unsafe { MaybeUninit::uninit().assume_init() }),
+        head: AtomicUsize::new(0),
+        tail: AtomicUsize::new(0),
+    });
+
+    (
+        Producer {
+            shared: shared.clone(),
+        },
+        Consumer { shared },
+    )
+}
+
+impl<T, const N: usize> Producer<T, N> {
+    /// Attempts to enqueue `val`, handing it back if the buffer is full.
+    pub fn try_enqueue(&mut self, val: T) -> Result<(), T> {
+        let tail = self.shared.tail.load(Ordering::Relaxed);
+        let next_tail = (tail + 1) % N;
+
+        if next_tail == self.shared.head.load(Ordering::Acquire) {
+            return Err(val);
+        }
+
+        unsafe {
+            (*self.shared.buf.get())[tail] = MaybeUninit::new(val);
+        }
+        self.shared.tail.store(next_tail, Ordering::Release);
+        Ok(())
+    }
+
+    /// Whether the buffer was full as of the last observed consumer index.
+    pub fn is_full(&self) -> bool {
+        let tail = self.shared.tail.load(Ordering::Relaxed);
+        let next_tail = (tail + 1) % N;
+        next_tail == self.shared.head.load(Ordering::Acquire)
+    }
+}
+
+impl<T, const N: usize> Consumer<T, N> {
+    /// Attempts to dequeue the oldest element, returning `None` if the
+    /// buffer is empty.
+    pub fn try_dequeue(&mut self) -> Option<T> {
+        let head = self.shared.head.load(Ordering::Relaxed);
+
+        if head == self.shared.tail.load(Ordering::Acquire) {
+            return None;
+        }
+
+        let val = unsafe { (*self.shared.buf.get())[head].assume_init_read() };
+        self.shared.head.store((head + 1) % N, Ordering::Release);
+        Some(val)
+    }
+
+    /// Whether the buffer was empty as of the last observed producer index.
+    pub fn is_empty(&self) -> bool {
+        let head = self.shared.head.load(Ordering::Relaxed);
+        head == self.shared.tail.load(Ordering::Acquire)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::spsc;
+
+    #[test]
+    fn test_enqueue_dequeue() {
+        let (mut tx, mut rx) = spsc::<u8, 4>();
+
+        assert!(rx.is_empty());
+        assert_eq!(tx.try_enqueue(1), Ok(()));
+        assert_eq!(tx.try_enqueue(2), Ok(()));
+        assert_eq!(tx.try_enqueue(3), Ok(()));
+
+        assert!(tx.is_full());
+        assert_eq!(tx.try_enqueue(4), Err(4));
+
+        assert_eq!(rx.try_dequeue(), Some(1));
+        assert_eq!(rx.try_dequeue(), Some(2));
+        assert_eq!(rx.try_dequeue(), Some(3));
+        assert_eq!(rx.try_dequeue(), None);
+        assert!(rx.is_empty());
+    }
+
+    #[test]
+    fn test_wraparound() {
+        // Capacity is N - 1 = 2, so the ring index has to cross the end of
+        // the backing buffer for this to exercise the wraparound math.
+        let (mut tx, mut rx) = spsc::<u8, 3>();
+
+        tx.try_enqueue(1).unwrap();
+        tx.try_enqueue(2).unwrap();
+        assert_eq!(rx.try_dequeue(), Some(1));
+        tx.try_enqueue(3).unwrap();
+
+        assert_eq!(rx.try_dequeue(), Some(2));
+        assert_eq!(rx.try_dequeue(), Some(3));
+        assert_eq!(rx.try_dequeue(), None);
+    }
+
+    #[test]
+    fn test_drop_with_elements_remaining() {
+        let (mut tx, rx) = spsc::<String, 4>();
+        tx.try_enqueue(String::from("a")).unwrap();
+        tx.try_enqueue(String::from("b")).unwrap();
+
+        drop(tx);
+        drop(rx);
+    }
+
+    #[test]
+    fn test_cross_thread_hand_off() {
+        use std::thread;
+
+        let (mut tx, mut rx) = spsc::<u32, 8>();
+
+        let producer = thread::spawn(move || {
+            for i in 0..1000 {
+                while tx.try_enqueue(i).is_err() {
+                    thread::yield_now();
+                }
+            }
+        });
+
+        let consumer = thread::spawn(move || {
+            let mut received = Vec::with_capacity(1000);
+            while received.len() < 1000 {
+                if let Some(v) = rx.try_dequeue() {
+                    received.push(v);
+                } else {
+                    thread::yield_now();
+                }
+            }
+            received
+        });
+
+        producer.join().unwrap();
+        let received = consumer.join().unwrap();
+        assert_eq!(received, (0..1000).collect::<Vec<u32>>());
+    }
+}